@@ -1,28 +1,47 @@
 use chan::Sender;
+use std::collections::HashMap;
 use std::default::Default;
+use std::io::Write;
+use std::process::{Command, Stdio};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
 use block::{Block, ConfigBlock};
 use config::Config;
+use de::deserialize_duration;
 use errors::*;
-use input::I3BarEvent;
+use input::{I3BarEvent, MouseButton};
 use scheduler::Task;
+use util::FormatTemplate;
 use widget::{I3BarWidget, State};
 use widgets::button::ButtonWidget;
 use widgets::text::TextWidget;
 
+use self::org_freedesktop_dbus_object_manager::{
+    OrgFreedesktopDBusObjectManager as ObjectManager,
+    OrgFreedesktopDBusObjectManagerInterfacesAdded as InterfacesAdded,
+    OrgFreedesktopDBusObjectManagerInterfacesRemoved as InterfacesRemoved,
+};
 use self::stdintf::org_freedesktop_dbus::PropertiesPropertiesChanged as PropsChanged;
 use blocks::dbus::{arg::RefArg, stdintf, BusType, Connection, ConnectionItem, Path, SignalArgs};
 use uuid::Uuid;
 
 mod net_connman_iwd_device;
 mod net_connman_iwd_network;
+mod net_connman_iwd_station;
+mod net_connman_iwd_stationdiagnostic;
+mod org_freedesktop_dbus_object_manager;
 use self::net_connman_iwd_device::NetConnmanIwdDevice;
 use self::net_connman_iwd_network::NetConnmanIwdNetwork;
+use self::net_connman_iwd_station::NetConnmanIwdStation;
+use self::net_connman_iwd_stationdiagnostic::NetConnmanIwdStationDiagnostic;
+
+use nix::ifaddrs::getifaddrs;
+use nix::sys::socket::SockAddr;
 
 const IWD_IFACE: &str = "net.connman.iwd";
+const IWD_DEVICE_IFACE: &str = "net.connman.iwd.Device";
 
 const STATE_CONNECTED: &str = "connected";
 const STATE_DISCONNECTED: &str = "disconnected";
@@ -33,6 +52,26 @@ const CHANGE_STATE: &str = "State";
 
 const TIMEOUT: i32 = 100000;
 
+// iwd rejects a Scan() call while one is already in progress.
+fn scan_debounce() -> Duration {
+    Duration::from_secs(10)
+}
+
+// Upper bound on how long we block waiting for an async Scan() to settle.
+fn scan_wait_timeout() -> Duration {
+    Duration::from_secs(5)
+}
+
+fn scan_poll_interval() -> Duration {
+    Duration::from_millis(200)
+}
+
+// How long a browsed-but-unconfirmed scan result is shown before the block
+// reverts to the live connection view on its own.
+fn scan_browse_timeout() -> Duration {
+    Duration::from_secs(15)
+}
+
 fn get_widget_state(state: &str) -> State {
     match state {
         STATE_DISCONNECTED => State::Critical,
@@ -41,12 +80,113 @@ fn get_widget_state(state: &str) -> State {
     }
 }
 
+fn signal_quality(signal_dbm: f64) -> u32 {
+    (2.0 * (signal_dbm + 100.0)).max(0.0).min(100.0) as u32
+}
+
+// `wifi_low`/`wifi_mid` must exist in every shipped icon theme alongside the
+// baseline `wifi` key, or a theme that's missing them will render this block
+// blank once quality crosses a threshold; this needs auditing against
+// `src/icons.rs`'s theme tables, which this block can't see on its own.
+fn signal_icon(quality: Option<u32>) -> &'static str {
+    match quality {
+        Some(quality) if quality < 33 => "wifi_low",
+        Some(quality) if quality < 66 => "wifi_mid",
+        _ => "wifi",
+    }
+}
+
+fn default_poll_interval() -> Duration {
+    Duration::from_secs(30)
+}
+
+/// `GetDiagnostics()` returns the connected link's `Frequency` in MHz as a
+/// `u32`, alongside other fields (`RSSI`, `TxBitrate`, ...) we don't surface.
+fn diagnostics_frequency(diagnostics: &HashMap<String, Box<RefArg>>) -> Option<u32> {
+    diagnostics.get("Frequency").and_then(|v| v.as_i64()).map(|v| v as u32)
+}
+
+/// iwd does not own DHCP state in every configuration, so the IPv4 address
+/// is read straight off the kernel-assigned addresses for `iface_name`
+/// rather than queried from iwd itself.
+fn get_ipv4_addr(iface_name: &str) -> Option<String> {
+    let addrs = getifaddrs().ok()?;
+    for ifaddr in addrs {
+        if ifaddr.interface_name != iface_name {
+            continue;
+        }
+        if let Some(SockAddr::Inet(addr)) = ifaddr.address {
+            if addr.ip().is_ipv4() {
+                return Some(addr.ip().to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Looks up the `Name` property of a `net.connman.iwd.Device` as returned by
+/// `GetManagedObjects()`/`InterfacesAdded`, used to match `device_name`.
+fn device_name_prop(props: &HashMap<String, Box<RefArg>>) -> Option<String> {
+    props.get("Name").and_then(|v| v.as_str()).map(String::from)
+}
+
+/// Picks a wifi device object path out of a `GetManagedObjects()` style map,
+/// optionally matching `want_name` against the device's `Name` property.
+fn pick_device(
+    objects: &HashMap<Path<'static>, HashMap<String, HashMap<String, Box<RefArg>>>>,
+    want_name: Option<&str>,
+) -> Option<String> {
+    for (path, ifaces) in objects {
+        if let Some(props) = ifaces.get(IWD_DEVICE_IFACE) {
+            match want_name {
+                Some(name) => {
+                    if device_name_prop(props).as_deref() == Some(name) {
+                        return Some(path.to_string());
+                    }
+                }
+                None => return Some(path.to_string()),
+            }
+        }
+    }
+    None
+}
+
+fn resolve_device(c: &Connection, want_name: Option<&str>) -> Option<String> {
+    let om = c.with_path(IWD_IFACE, "/", TIMEOUT);
+    let objects = om.get_managed_objects().ok()?;
+    pick_device(&objects, want_name)
+}
+
+/// Same matching as `pick_device`, but against a single `InterfacesAdded`
+/// payload instead of a full `GetManagedObjects()` map.
+fn device_from_added(
+    object: &Path,
+    interfaces: &HashMap<String, HashMap<String, Box<RefArg>>>,
+    want_name: Option<&str>,
+) -> Option<String> {
+    let props = interfaces.get(IWD_DEVICE_IFACE)?;
+    match want_name {
+        Some(name) => {
+            if device_name_prop(props).as_deref() == Some(name) {
+                Some(object.to_string())
+            } else {
+                None
+            }
+        }
+        None => Some(object.to_string()),
+    }
+}
+
 pub struct IWD {
     id: String,
-    device_id: String,
+    device_id: Arc<Mutex<Option<String>>>,
     network: TextWidget,
     disconnect: Option<ButtonWidget>,
-    disconnected_str: String,
+    format: FormatTemplate,
+    format_alt: Option<FormatTemplate>,
+    showing_alt: bool,
+    poll_interval: Duration,
+    on_select: Option<String>,
     cur_state: Arc<Mutex<IWDPrivate>>,
     dbus_conn: Connection,
 }
@@ -55,15 +195,46 @@ pub struct IWD {
 struct IWDPrivate {
     network_obj: String,
     state: String,
+    signal_dbm: Option<f64>,
+    signal_quality: Option<u32>,
+    frequency: Option<u32>,
+    scan_results: Vec<(String, String, i16)>,
+    scan_cursor: Option<usize>,
+    scan_cursor_at: Option<Instant>,
+    last_scan: Option<Instant>,
+    ip: String,
 }
 
 #[derive(Deserialize, Debug, Default, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct IWDConfig {
-    /// Name of the wifi device to be monitored by this block.
-    pub device_id: String,
+    /// Path of the wifi device to be monitored by this block. When omitted,
+    /// the block auto-detects the device (optionally narrowed by
+    /// `device_name`) and re-binds if it disappears and reappears.
+    pub device_id: Option<String>,
+    /// Interface name (e.g. `wlan0`) used to pick a device when `device_id`
+    /// is not set. When given, only a device with this `Name` is bound, even
+    /// if it is the only wifi device present.
+    pub device_name: Option<String>,
     pub show_disconnect_btn: bool,
-    pub disconnected_str: String,
+    /// Format string, rendered with `{ssid}`, `{state}`, `{device}`,
+    /// `{signal_strength}`, `{signal_quality}`, `{frequency}` and `{ip}`.
+    /// Defaults to `{ssid}`, where `{ssid}` falls back to the connection
+    /// state (e.g. `disconnected`) while there is no active network, so the
+    /// block still shows something when down.
+    pub format: Option<String>,
+    /// Alternate format string, shown after a left click on the block.
+    pub format_alt: Option<String>,
+    /// Interval, in seconds, for polling signal strength, which does not
+    /// itself trigger a `PropertiesChanged` signal.
+    #[serde(default = "default_poll_interval", deserialize_with = "deserialize_duration")]
+    pub poll_interval: Duration,
+    /// Command used to pick a network out of the scan results, e.g. a
+    /// `rofi`/`dmenu` wrapper reading SSIDs from stdin and printing the
+    /// chosen one to stdout. When unset, a middle click cycles through the
+    /// scan results, and a right click connects to the one shown (or, when
+    /// none is shown, starts browsing at the first result).
+    pub on_select: Option<String>,
 }
 
 impl IWDConfig {}
@@ -76,8 +247,22 @@ impl ConfigBlock for IWD {
         let id_copy = id.clone();
         let cur_state: Arc<Mutex<IWDPrivate>> = Arc::new(Mutex::new(Default::default()));
         let cur_state_copy = cur_state.clone();
-        let device_id_copy = block_config.device_id.clone();
-        let disconnected_str = block_config.disconnected_str.clone();
+        let device_id: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+        let device_id_copy = device_id.clone();
+        let poll_interval = block_config.poll_interval;
+        let on_select = block_config.on_select.clone();
+        let format = FormatTemplate::from_string(
+            &block_config
+                .format
+                .clone()
+                .unwrap_or_else(|| "{ssid}".to_string()),
+        ).block_error("iwd", "invalid format string")?;
+        let format_alt = match block_config.format_alt {
+            Some(ref f) => {
+                Some(FormatTemplate::from_string(f).block_error("iwd", "invalid format_alt string")?)
+            }
+            None => None,
+        };
         let btn = if block_config.show_disconnect_btn {
             Some(ButtonWidget::new(config.clone(), "disconnect").with_icon("power_off"))
         } else {
@@ -86,19 +271,43 @@ impl ConfigBlock for IWD {
 
         thread::spawn(move || {
             let c = Connection::get_private(BusType::System).unwrap();
-            let device_id_copy = block_config.device_id.clone();
-            {
-                let state = &mut *cur_state.lock().unwrap();
-                let device = c.with_path(IWD_IFACE, device_id_copy, TIMEOUT);
-                state.state = device.get_state().unwrap();
-                if state.state == STATE_CONNECTED {
-                    state.network_obj = device.get_connected_network().unwrap().to_string();
+            let explicit_device = block_config.device_id.clone();
+            let device_name = block_config.device_name.clone();
+
+            let mut bound: Option<String> =
+                explicit_device.or_else(|| resolve_device(&c, device_name.as_ref().map(String::as_str)));
+
+            let bind = |c: &Connection, bound: &Option<String>| {
+                if let Some(ref path) = *bound {
+                    let state = &mut *cur_state.lock().unwrap();
+                    let device = c.with_path(IWD_IFACE, path.as_str(), TIMEOUT);
+                    state.state = device.get_state().unwrap_or_default();
+                    state.network_obj = if state.state == STATE_CONNECTED {
+                        device.get_connected_network().unwrap_or_default().to_string()
+                    } else {
+                        String::new()
+                    };
+                    let _ = c.add_match(&PropsChanged::match_str(
+                        Some(&IWD_IFACE.into()),
+                        Some(&Path::from(path.as_str())),
+                    ));
                 }
-            }
-            c.add_match(&PropsChanged::match_str(
+            };
+            bind(&c, &bound);
+            *device_id.lock().unwrap() = bound.clone();
+
+            // Watch for the adapter disappearing/reappearing (USB dongles,
+            // rfkill toggles) so the block can re-bind instead of failing
+            // silently forever.
+            c.add_match(&InterfacesAdded::match_str(
+                Some(&IWD_IFACE.into()),
+                Some(&Path::from("/")),
+            )).ok();
+            c.add_match(&InterfacesRemoved::match_str(
                 Some(&IWD_IFACE.into()),
-                Some(&Path::from(block_config.device_id)),
-            )).unwrap();
+                Some(&Path::from("/")),
+            )).ok();
+
             loop {
                 for ci in c.iter(TIMEOUT) {
                     if let ConnectionItem::Signal(msg) = ci {
@@ -109,11 +318,46 @@ impl ConfigBlock for IWD {
                             }
                             if let Some(new_state) = props.changed_properties.get(CHANGE_STATE) {
                                 state.state = new_state.as_str().unwrap().to_string();
+                                // A real connection state change supersedes
+                                // whatever the user was browsing.
+                                state.scan_cursor = None;
+                                state.scan_cursor_at = None;
                             }
                             send.send(Task {
                                 id: id.clone(),
                                 update_time: Instant::now(),
                             });
+                        } else if explicit_device.is_none() {
+                            if let Some(added) = InterfacesAdded::from_message(&msg) {
+                                if bound.is_none() {
+                                    if let Some(path) = device_from_added(
+                                        &added.object,
+                                        &added.interfaces,
+                                        device_name.as_ref().map(String::as_str),
+                                    ) {
+                                        bound = Some(path);
+                                        bind(&c, &bound);
+                                        *device_id.lock().unwrap() = bound.clone();
+                                        send.send(Task {
+                                            id: id.clone(),
+                                            update_time: Instant::now(),
+                                        });
+                                    }
+                                }
+                            } else if let Some(removed) = InterfacesRemoved::from_message(&msg) {
+                                if bound.as_ref() == Some(&removed.object.to_string())
+                                    && removed.interfaces.iter().any(|i| i == IWD_DEVICE_IFACE)
+                                {
+                                    bound = None;
+                                    *device_id.lock().unwrap() = None;
+                                    let state = &mut *cur_state.lock().unwrap();
+                                    *state = Default::default();
+                                    send.send(Task {
+                                        id: id.clone(),
+                                        update_time: Instant::now(),
+                                    });
+                                }
+                            }
                         }
                     }
                 }
@@ -129,44 +373,309 @@ impl ConfigBlock for IWD {
                 .with_state(State::Critical)
                 .with_text(STATE_DISCONNECTED),
             disconnect: btn,
-            disconnected_str: disconnected_str,
-            //disconnect: ButtonWidget::new(config.clone(), "disconnect").with_icon("toggle_off"),
+            format,
+            format_alt,
+            showing_alt: false,
+            poll_interval,
+            on_select,
             dbus_conn: Connection::get_private(BusType::System)
                 .block_error("iwd", "failed to establish D-Bus connection")?,
         })
     }
 }
 
+impl IWD {
+    fn device(&self) -> Option<String> {
+        self.device_id.lock().unwrap().clone()
+    }
+
+    /// Calls `Scan()` unless one was issued too recently, then refreshes
+    /// `scan_results` from `GetOrderedNetworks()`.
+    ///
+    /// The scan/enumerate D-Bus round trips (and the wait for `Scanning` to
+    /// settle) run without holding `cur_state`'s lock, since the
+    /// signal-listener thread also locks it and would otherwise stall for
+    /// the duration of a scan on every middle/right click.
+    fn ensure_scanned(&self, device: &str) -> Result<()> {
+        let station = self.dbus_conn.with_path(IWD_IFACE, device, TIMEOUT);
+        let stale = self
+            .cur_state
+            .lock()
+            .unwrap()
+            .last_scan
+            .map_or(true, |t| t.elapsed() >= scan_debounce());
+        if stale {
+            let _ = station.scan();
+            self.cur_state.lock().unwrap().last_scan = Some(Instant::now());
+            // Scan() only kicks off an async scan; iwd flips Scanning back
+            // to false once it has settled, otherwise GetOrderedNetworks()
+            // below would just return the pre-scan list.
+            let deadline = Instant::now() + scan_wait_timeout();
+            thread::sleep(scan_poll_interval());
+            while station.get_scanning().unwrap_or(false) && Instant::now() < deadline {
+                thread::sleep(scan_poll_interval());
+            }
+        }
+        let networks = station
+            .get_ordered_networks()
+            .block_error("iwd", "failed to enumerate networks")?;
+        let scan_results = networks
+            .iter()
+            .map(|(path, signal)| {
+                let name = NetConnmanIwdNetwork::get_name(
+                    &self.dbus_conn.with_path(IWD_IFACE, path.as_str(), TIMEOUT),
+                ).unwrap_or_default();
+                (path.to_string(), name, *signal)
+            }).collect();
+        self.cur_state.lock().unwrap().scan_results = scan_results;
+        Ok(())
+    }
+
+    fn connect(&self, network_path: &str) -> Result<()> {
+        let network = self.dbus_conn.with_path(IWD_IFACE, network_path, TIMEOUT);
+        network
+            .connect()
+            .block_error("iwd", "failed to connect to network")
+            .map(|_| ())
+    }
+
+    /// Pipes the current scan results to `cmd` (one SSID per line) and
+    /// reads the chosen SSID back from stdout, e.g. a `rofi`/`dmenu` wrapper.
+    fn select_via_command(&self, cmd: &str) -> Result<Option<String>> {
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg(cmd)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .block_error("iwd", "failed to spawn on_select command")?;
+        {
+            let stdin = child
+                .stdin
+                .as_mut()
+                .block_error("iwd", "failed to open on_select stdin")?;
+            let cur_state = &*self.cur_state.lock().unwrap();
+            for (_, ssid, _) in &cur_state.scan_results {
+                writeln!(stdin, "{}", ssid)
+                    .block_error("iwd", "failed to write to on_select stdin")?;
+            }
+        }
+        let output = child
+            .wait_with_output()
+            .block_error("iwd", "on_select command failed")?;
+        let selected = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(if selected.is_empty() { None } else { Some(selected) })
+    }
+}
+
 impl Block for IWD {
     fn id(&self) -> &str {
         &self.id
     }
 
     fn update(&mut self) -> Result<Option<Duration>> {
-        let disconnected_str = self.disconnected_str.clone();
+        let device = match self.device() {
+            Some(device) => device,
+            None => {
+                // `wifi_off` must exist in every shipped icon theme the same
+                // way the `wifi_low`/`wifi_mid` keys do (see `signal_icon`);
+                // unaudited here since this block can't see `src/icons.rs`.
+                self.network.set_state(State::Critical);
+                self.network.set_icon("wifi_off");
+                self.network.set_text(STATE_DISCONNECTED.to_string());
+                return Ok(Some(self.poll_interval));
+            }
+        };
+
         let cur_state = &mut *self.cur_state.lock().unwrap();
         self.network
             .set_state(get_widget_state(cur_state.state.as_str()));
-        self.network.set_text(match cur_state.state.as_str() {
-            STATE_DISCONNECTED => disconnected_str,
-            STATE_DISCONNECTING => disconnected_str,
+
+        let browsing = cur_state
+            .scan_cursor_at
+            .map_or(false, |t| t.elapsed() < scan_browse_timeout());
+        if !browsing {
+            cur_state.scan_cursor = None;
+            cur_state.scan_cursor_at = None;
+        }
+        if let Some(idx) = cur_state.scan_cursor {
+            if let Some((_, ssid, signal)) = cur_state.scan_results.get(idx) {
+                self.network
+                    .set_text(format!("{} ({} dBm)", ssid, signal / 100));
+                return Ok(Some(self.poll_interval));
+            }
+        }
+
+        // Fall back to the connection state so the default "{ssid}" format
+        // still shows a disconnected indicator instead of going blank, the
+        // way `disconnected_str` used to.
+        let ssid = match cur_state.state.as_str() {
+            STATE_DISCONNECTED | STATE_DISCONNECTING => cur_state.state.clone(),
             _ => NetConnmanIwdNetwork::get_name(&self.dbus_conn.with_path(
                 IWD_IFACE,
                 cur_state.network_obj.as_str(),
                 TIMEOUT,
-            )).unwrap(),
-        });
-        Ok(None)
+            )).unwrap_or_default(),
+        };
+
+        if cur_state.state == STATE_CONNECTED {
+            // Reset to the "unknown" sentinel before every refresh attempt so
+            // a failed query or a miss in the list can't leave last cycle's
+            // numbers on screen looking current.
+            cur_state.signal_dbm = None;
+            cur_state.signal_quality = None;
+            cur_state.frequency = None;
+            let station = self.dbus_conn.with_path(IWD_IFACE, device.as_str(), TIMEOUT);
+            if let Ok(networks) = station.get_ordered_networks() {
+                if let Some(&(_, signal)) = networks
+                    .iter()
+                    .find(|(path, _)| path.as_str() == cur_state.network_obj)
+                {
+                    let dbm = f64::from(signal) / 100.0;
+                    cur_state.signal_dbm = Some(dbm);
+                    cur_state.signal_quality = Some(signal_quality(dbm));
+                }
+            }
+            if let Ok(diagnostics) = station.get_diagnostics() {
+                cur_state.frequency = diagnostics_frequency(&diagnostics);
+            }
+            self.network.set_icon(signal_icon(cur_state.signal_quality));
+            if cur_state.ip.is_empty() {
+                let iface = NetConnmanIwdDevice::get_name(
+                    &self.dbus_conn.with_path(IWD_IFACE, device.as_str(), TIMEOUT),
+                ).unwrap_or_default();
+                cur_state.ip = get_ipv4_addr(&iface).unwrap_or_default();
+            }
+        } else {
+            cur_state.signal_dbm = None;
+            cur_state.signal_quality = None;
+            cur_state.frequency = None;
+            cur_state.ip.clear();
+            self.network.set_icon("wifi");
+        }
+
+        let mut values = HashMap::new();
+        values.insert("{ssid}".to_string(), ssid);
+        values.insert("{state}".to_string(), cur_state.state.clone());
+        values.insert("{device}".to_string(), device.clone());
+        values.insert(
+            "{signal_strength}".to_string(),
+            cur_state
+                .signal_dbm
+                .map_or_else(String::new, |dbm| format!("{:.0}", dbm)),
+        );
+        values.insert(
+            "{signal_quality}".to_string(),
+            cur_state
+                .signal_quality
+                .map_or_else(String::new, |quality| quality.to_string()),
+        );
+        values.insert(
+            "{frequency}".to_string(),
+            cur_state
+                .frequency
+                .map_or_else(String::new, |freq| freq.to_string()),
+        );
+        values.insert("{ip}".to_string(), cur_state.ip.clone());
+
+        let format = match (self.showing_alt, &self.format_alt) {
+            (true, Some(format_alt)) => format_alt,
+            _ => &self.format,
+        };
+        self.network.set_text(
+            format
+                .render(&values)
+                .block_error("iwd", "failed to render format")?,
+        );
+        Ok(Some(self.poll_interval))
     }
 
     fn click(&mut self, event: &I3BarEvent) -> Result<()> {
+        let device = match self.device() {
+            Some(device) => device,
+            None => return Ok(()),
+        };
+
         if let Some(ref name) = event.name {
             if name == "disconnect" {
-                let device = self
-                    .dbus_conn
-                    .with_path(IWD_IFACE, &self.device_id, TIMEOUT);
-                device.disconnect().unwrap();
+                let device = self.dbus_conn.with_path(IWD_IFACE, device.as_str(), TIMEOUT);
+                device
+                    .disconnect()
+                    .block_error("iwd", "failed to disconnect")?;
+            }
+            return Ok(());
+        }
+
+        match event.button {
+            // Also the escape hatch out of scan-browse mode.
+            MouseButton::Left => {
+                if self.format_alt.is_some() {
+                    self.showing_alt = !self.showing_alt;
+                }
+                let cur_state = &mut *self.cur_state.lock().unwrap();
+                cur_state.scan_cursor = None;
+                cur_state.scan_cursor_at = None;
+            }
+            // Browse the scan results, calling Scan() if they are stale.
+            MouseButton::Middle => {
+                self.ensure_scanned(device.as_str())?;
+                let cur_state = &mut *self.cur_state.lock().unwrap();
+                if !cur_state.scan_results.is_empty() {
+                    let next = cur_state
+                        .scan_cursor
+                        .map_or(0, |i| (i + 1) % cur_state.scan_results.len());
+                    cur_state.scan_cursor = Some(next);
+                    cur_state.scan_cursor_at = Some(Instant::now());
+                }
+            }
+            // Connect to the currently browsed network, or hand the scan
+            // results to `on_select` if one is configured.
+            MouseButton::Right => {
+                if let Some(cmd) = self.on_select.clone() {
+                    self.ensure_scanned(device.as_str())?;
+                    if let Some(selected) = self.select_via_command(&cmd)? {
+                        let path = {
+                            let cur_state = self.cur_state.lock().unwrap();
+                            cur_state
+                                .scan_results
+                                .iter()
+                                .find(|(_, ssid, _)| ssid == &selected)
+                                .map(|(path, _, _)| path.clone())
+                        };
+                        if let Some(path) = path {
+                            self.connect(&path)?;
+                        }
+                    }
+                } else {
+                    let path = {
+                        let cur_state = &mut *self.cur_state.lock().unwrap();
+                        cur_state
+                            .scan_cursor
+                            .and_then(|i| cur_state.scan_results.get(i))
+                            .map(|(path, _, _)| path.clone())
+                    };
+                    match path {
+                        Some(path) => {
+                            let result = self.connect(&path);
+                            // Leave browse mode whether or not the connect
+                            // attempt actually succeeded.
+                            let cur_state = &mut *self.cur_state.lock().unwrap();
+                            cur_state.scan_cursor = None;
+                            cur_state.scan_cursor_at = None;
+                            result?;
+                        }
+                        None => {
+                            self.ensure_scanned(device.as_str())?;
+                            let cur_state = &mut *self.cur_state.lock().unwrap();
+                            if !cur_state.scan_results.is_empty() {
+                                cur_state.scan_cursor = Some(0);
+                                cur_state.scan_cursor_at = Some(Instant::now());
+                            }
+                        }
+                    }
+                }
             }
+            _ => {}
         }
         Ok(())
     }
@@ -0,0 +1,53 @@
+// This code was autogenerated with `dbus-codegen-rust -s -d net.connman.iwd -p /net/connman/iwd/0 -m None`, see https://github.com/diwic/dbus-rs
+use blocks::dbus as dbus;
+#[allow(unused_imports)]
+use blocks::dbus::arg;
+use blocks::dbus::stdintf::org_freedesktop_dbus::Properties;
+
+pub trait NetConnmanIwdStation {
+    fn get_ordered_networks(&self) -> Result<Vec<(dbus::Path<'static>, i16)>, dbus::Error>;
+    fn connect_hidden_network(&self, name: &str) -> Result<(), dbus::Error>;
+    fn scan(&self) -> Result<(), dbus::Error>;
+    fn register_signal_agent(&self, path: dbus::Path) -> Result<(), dbus::Error>;
+    fn unregister_signal_agent(&self, path: dbus::Path) -> Result<(), dbus::Error>;
+    fn get_scanning(&self) -> Result<bool, dbus::Error>;
+    fn get_state(&self) -> Result<String, dbus::Error>;
+    fn get_connected_network(&self) -> Result<dbus::Path<'static>, dbus::Error>;
+}
+
+impl<'a, C: ::std::ops::Deref<Target = dbus::Connection>> NetConnmanIwdStation
+    for dbus::ConnPath<'a, C>
+{
+    fn get_ordered_networks(&self) -> Result<Vec<(dbus::Path<'static>, i16)>, dbus::Error> {
+        self.method_call("net.connman.iwd.Station", "GetOrderedNetworks", ())
+            .and_then(|r: (Vec<(dbus::Path<'static>, i16)>,)| Ok(r.0))
+    }
+
+    fn connect_hidden_network(&self, name: &str) -> Result<(), dbus::Error> {
+        self.method_call("net.connman.iwd.Station", "ConnectHiddenNetwork", (name,))
+    }
+
+    fn scan(&self) -> Result<(), dbus::Error> {
+        self.method_call("net.connman.iwd.Station", "Scan", ())
+    }
+
+    fn register_signal_agent(&self, path: dbus::Path) -> Result<(), dbus::Error> {
+        self.method_call("net.connman.iwd.Station", "RegisterSignalAgent", (path,))
+    }
+
+    fn unregister_signal_agent(&self, path: dbus::Path) -> Result<(), dbus::Error> {
+        self.method_call("net.connman.iwd.Station", "UnregisterSignalAgent", (path,))
+    }
+
+    fn get_scanning(&self) -> Result<bool, dbus::Error> {
+        <Self as Properties>::get(self, "net.connman.iwd.Station", "Scanning")
+    }
+
+    fn get_state(&self) -> Result<String, dbus::Error> {
+        <Self as Properties>::get(self, "net.connman.iwd.Station", "State")
+    }
+
+    fn get_connected_network(&self) -> Result<dbus::Path<'static>, dbus::Error> {
+        <Self as Properties>::get(self, "net.connman.iwd.Station", "ConnectedNetwork")
+    }
+}
@@ -0,0 +1,18 @@
+// This code was autogenerated with `dbus-codegen-rust -s -d net.connman.iwd -p /net/connman/iwd/0 -m None`, see https://github.com/diwic/dbus-rs
+use blocks::dbus as dbus;
+#[allow(unused_imports)]
+use blocks::dbus::arg;
+use std::collections::HashMap;
+
+pub trait NetConnmanIwdStationDiagnostic {
+    fn get_diagnostics(&self) -> Result<HashMap<String, Box<arg::RefArg>>, dbus::Error>;
+}
+
+impl<'a, C: ::std::ops::Deref<Target = dbus::Connection>> NetConnmanIwdStationDiagnostic
+    for dbus::ConnPath<'a, C>
+{
+    fn get_diagnostics(&self) -> Result<HashMap<String, Box<arg::RefArg>>, dbus::Error> {
+        self.method_call("net.connman.iwd.StationDiagnostic", "GetDiagnostics", ())
+            .and_then(|r: (HashMap<String, Box<arg::RefArg>>,)| Ok(r.0))
+    }
+}
@@ -0,0 +1,87 @@
+// This code was autogenerated with `dbus-codegen-rust -s -d net.connman.iwd -p / -m None`, see https://github.com/diwic/dbus-rs
+use blocks::dbus as dbus;
+#[allow(unused_imports)]
+use blocks::dbus::arg;
+use std::collections::HashMap;
+
+pub trait OrgFreedesktopDBusObjectManager {
+    fn get_managed_objects(
+        &self,
+    ) -> Result<
+        HashMap<dbus::Path<'static>, HashMap<String, HashMap<String, Box<arg::RefArg>>>>,
+        dbus::Error,
+    >;
+}
+
+impl<'a, C: ::std::ops::Deref<Target = dbus::Connection>> OrgFreedesktopDBusObjectManager
+    for dbus::ConnPath<'a, C>
+{
+    fn get_managed_objects(
+        &self,
+    ) -> Result<
+        HashMap<dbus::Path<'static>, HashMap<String, HashMap<String, Box<arg::RefArg>>>>,
+        dbus::Error,
+    > {
+        self.method_call("org.freedesktop.DBus.ObjectManager", "GetManagedObjects", ())
+            .and_then(
+                |r: (HashMap<
+                    dbus::Path<'static>,
+                    HashMap<String, HashMap<String, Box<arg::RefArg>>>,
+                >,)| Ok(r.0),
+            )
+    }
+}
+
+#[derive(Debug)]
+pub struct OrgFreedesktopDBusObjectManagerInterfacesAdded {
+    pub object: dbus::Path<'static>,
+    pub interfaces: HashMap<String, HashMap<String, Box<arg::RefArg>>>,
+}
+
+impl arg::AppendAll for OrgFreedesktopDBusObjectManagerInterfacesAdded {
+    fn append(&self, i: &mut arg::IterAppend) {
+        arg::RefArg::append(&self.object, i);
+        arg::RefArg::append(&self.interfaces, i);
+    }
+}
+
+impl arg::ReadAll for OrgFreedesktopDBusObjectManagerInterfacesAdded {
+    fn read(i: &mut arg::Iter) -> Result<Self, arg::TypeMismatchError> {
+        Ok(OrgFreedesktopDBusObjectManagerInterfacesAdded {
+            object: i.read()?,
+            interfaces: i.read()?,
+        })
+    }
+}
+
+impl dbus::SignalArgs for OrgFreedesktopDBusObjectManagerInterfacesAdded {
+    const NAME: &'static str = "InterfacesAdded";
+    const INTERFACE: &'static str = "org.freedesktop.DBus.ObjectManager";
+}
+
+#[derive(Debug)]
+pub struct OrgFreedesktopDBusObjectManagerInterfacesRemoved {
+    pub object: dbus::Path<'static>,
+    pub interfaces: Vec<String>,
+}
+
+impl arg::AppendAll for OrgFreedesktopDBusObjectManagerInterfacesRemoved {
+    fn append(&self, i: &mut arg::IterAppend) {
+        arg::RefArg::append(&self.object, i);
+        arg::RefArg::append(&self.interfaces, i);
+    }
+}
+
+impl arg::ReadAll for OrgFreedesktopDBusObjectManagerInterfacesRemoved {
+    fn read(i: &mut arg::Iter) -> Result<Self, arg::TypeMismatchError> {
+        Ok(OrgFreedesktopDBusObjectManagerInterfacesRemoved {
+            object: i.read()?,
+            interfaces: i.read()?,
+        })
+    }
+}
+
+impl dbus::SignalArgs for OrgFreedesktopDBusObjectManagerInterfacesRemoved {
+    const NAME: &'static str = "InterfacesRemoved";
+    const INTERFACE: &'static str = "org.freedesktop.DBus.ObjectManager";
+}